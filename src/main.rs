@@ -1,11 +1,32 @@
 /// Experiment to see if deref coercion will allow multiply-nested wrappers to expose
 /// all related trait methods regardless of nesting order
-use std::{any::TypeId, borrow::Cow, marker::PhantomData, ops::Deref};
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
 // Peano numbers
 struct Z;
 struct S<T>(PhantomData<T>);
 
+// Core wrapper abstraction
+// A single-field newtype that holds some `Content`. The blanket `Construct`,
+// `Find`, `FindMut`, `UnwrapMut` and `Innermost` impls below are written against
+// this trait, so a new layer gets those from its `impl Wrapper` alone. `Deref`
+// and `DerefMut` (which coherence keeps us from blanketing), the recursive
+// `With` arm, `MapInner`, `Drive`, `DriveMut` and `Transparent` are still
+// implemented once per wrapper.
+trait Wrapper {
+    type Content;
+
+    fn content(&self) -> &Self::Content;
+    fn content_mut(&mut self) -> &mut Self::Content;
+    fn into_content(self) -> Self::Content;
+    fn wrap(content: Self::Content) -> Self;
+}
+
 // Usage wrapper
 // Tags a type as being related to some other type
 enum UsageTag {}
@@ -24,11 +45,37 @@ impl<U, T> Usage<U, T> {
     }
 }
 
+impl<U, T> Wrapper for Usage<U, T> {
+    type Content = T;
+
+    fn content(&self) -> &T {
+        &self.data
+    }
+
+    fn content_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    fn into_content(self) -> T {
+        self.data
+    }
+
+    fn wrap(content: T) -> Self {
+        Usage::new(content)
+    }
+}
+
 impl<U, T> Deref for Usage<U, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.data
+        self.content()
+    }
+}
+
+impl<U, T> DerefMut for Usage<U, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.content_mut()
     }
 }
 
@@ -64,11 +111,37 @@ impl<T> ChangedWrap<T> {
     }
 }
 
+impl<T> Wrapper for ChangedWrap<T> {
+    type Content = T;
+
+    fn content(&self) -> &T {
+        &self.data
+    }
+
+    fn content_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    fn into_content(self) -> T {
+        self.data
+    }
+
+    fn wrap(content: T) -> Self {
+        ChangedWrap::new(content)
+    }
+}
+
 impl<T> Deref for ChangedWrap<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.data
+        self.content()
+    }
+}
+
+impl<T> DerefMut for ChangedWrap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.content_mut()
     }
 }
 
@@ -106,11 +179,37 @@ impl<T> LabelWrap<T> {
     }
 }
 
+impl<T> Wrapper for LabelWrap<T> {
+    type Content = T;
+
+    fn content(&self) -> &T {
+        &self.data
+    }
+
+    fn content_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    fn into_content(self) -> T {
+        self.data
+    }
+
+    fn wrap(content: T) -> Self {
+        LabelWrap::new(content)
+    }
+}
+
 impl<T> Deref for LabelWrap<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        &self.data
+        self.content()
+    }
+}
+
+impl<T> DerefMut for LabelWrap<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.content_mut()
     }
 }
 
@@ -152,48 +251,24 @@ impl<V, T> Construct<V, Z> for T where T: InnerType<InnerType = V> {
 }
 */
 
-impl<T, U> Construct<T, Z> for Usage<U, T> {
-    fn construct(t: T) -> Self {
-        Usage::new(t)
-    }
-}
-
-impl<T, I, U, N> Construct<T, S<I>> for Usage<U, N>
-where
-    N: Construct<T, I>,
-{
-    fn construct(t: T) -> Self {
-        Usage::new(N::construct(t))
-    }
-}
-
-impl<T> Construct<T, Z> for LabelWrap<T> {
-    fn construct(t: T) -> Self {
-        LabelWrap::new(t)
-    }
-}
-
-impl<T, I, N> Construct<T, S<I>> for LabelWrap<N>
+// Base case: a wrapper whose content is the leaf value we're constructing.
+impl<W, T> Construct<T, Z> for W
 where
-    N: Construct<T, I>,
+    W: Wrapper<Content = T>,
 {
     fn construct(t: T) -> Self {
-        LabelWrap::new(N::construct(t))
-    }
-}
-
-impl<T> Construct<T, Z> for ChangedWrap<T> {
-    fn construct(t: T) -> Self {
-        ChangedWrap::new(t)
+        W::wrap(t)
     }
 }
 
-impl<T, I, N> Construct<T, S<I>> for ChangedWrap<N>
+// Recursive case: descend through one wrapper layer and construct the rest.
+impl<W, T, I, N> Construct<T, S<I>> for W
 where
+    W: Wrapper<Content = N>,
     N: Construct<T, I>,
 {
     fn construct(t: T) -> Self {
-        ChangedWrap::new(N::construct(t))
+        W::wrap(N::construct(t))
     }
 }
 
@@ -252,10 +327,406 @@ where
     }
 }
 
+// Utility trait for type-directed lookup of a layer within the stack
+// The index `I` disambiguates the depth and is inferred exactly like in
+// `Construct`, so a given wrapper type resolves regardless of its position.
+trait Find<W, I> {
+    fn find(&self) -> &W;
+}
+
+// Base case: we've reached the requested layer.
+impl<W> Find<W, Z> for W {
+    fn find(&self) -> &W {
+        self
+    }
+}
+
+// Recursive case: descend one layer and keep looking.
+impl<W, I, Outer> Find<W, S<I>> for Outer
+where
+    Outer: Wrapper,
+    Outer::Content: Find<W, I>,
+{
+    fn find(&self) -> &W {
+        self.content().find()
+    }
+}
+
+// Mutable sibling of `Find`
+trait FindMut<W, I> {
+    fn find_mut(&mut self) -> &mut W;
+}
+
+impl<W> FindMut<W, Z> for W {
+    fn find_mut(&mut self) -> &mut W {
+        self
+    }
+}
+
+impl<W, I, Outer> FindMut<W, S<I>> for Outer
+where
+    Outer: Wrapper,
+    Outer::Content: FindMut<W, I>,
+{
+    fn find_mut(&mut self) -> &mut W {
+        self.content_mut().find_mut()
+    }
+}
+
+// Utility trait for peeling every wrapper layer down to the core value
+// Unlike `FindMut`, the destination is driven by the stack's own structure: we
+// recurse while the content is itself a wrapper and stop at the leaf payload,
+// so no index or type annotation is needed to reach the bottom.
+trait UnwrapMut {
+    type Inner;
+    fn innermost_mut(&mut self) -> &mut Self::Inner;
+}
+
+// Recursive case: this is a wrapper, so peel one layer and keep going.
+impl<W> UnwrapMut for W
+where
+    W: Wrapper,
+    W::Content: UnwrapMut,
+{
+    type Inner = <W::Content as UnwrapMut>::Inner;
+
+    fn innermost_mut(&mut self) -> &mut Self::Inner {
+        self.content_mut().innermost_mut()
+    }
+}
+
+// Base cases: the leaf payloads these stacks are built around.
+macro_rules! impl_unwrap_mut_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl UnwrapMut for $t {
+                type Inner = $t;
+
+                fn innermost_mut(&mut self) -> &mut Self::Inner {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_unwrap_mut_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Utility trait for consuming a stack down to its core value
+// The recursive arm is blanket over `Wrapper`, mirroring `UnwrapMut`; the leaf
+// payloads terminate the recursion by returning themselves.
+trait Innermost {
+    type Inner;
+    fn into_innermost(self) -> Self::Inner;
+}
+
+impl<W, N> Innermost for W
+where
+    W: Wrapper<Content = N>,
+    N: Innermost,
+{
+    type Inner = N::Inner;
+
+    fn into_innermost(self) -> Self::Inner {
+        self.into_content().into_innermost()
+    }
+}
+
+macro_rules! impl_innermost_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Innermost for $t {
+                type Inner = $t;
+
+                fn into_innermost(self) -> Self::Inner {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_innermost_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Structure-preserving transform of the core value
+// Each wrapper rebuilds itself around the mapped content, carrying its own
+// metadata (the `changed` flag, the label, the usage tag) forward unchanged, so
+// the stack's shape survives while only the innermost payload changes type.
+trait MapInner<U>: Innermost {
+    type Output;
+    fn map_inner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Inner) -> U;
+}
+
+// Recursive arms: one per wrapper, preserving that layer's metadata.
+impl<U, M, N> MapInner<U> for Usage<M, N>
+where
+    N: MapInner<U>,
+{
+    type Output = Usage<M, N::Output>;
+
+    fn map_inner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Inner) -> U,
+    {
+        Usage {
+            data: self.data.map_inner(f),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<U, N> MapInner<U> for ChangedWrap<N>
+where
+    N: MapInner<U>,
+{
+    type Output = ChangedWrap<N::Output>;
+
+    fn map_inner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Inner) -> U,
+    {
+        ChangedWrap {
+            data: self.data.map_inner(f),
+            changed: self.changed,
+        }
+    }
+}
+
+impl<U, N> MapInner<U> for LabelWrap<N>
+where
+    N: MapInner<U>,
+{
+    type Output = LabelWrap<N::Output>;
+
+    fn map_inner<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Inner) -> U,
+    {
+        LabelWrap {
+            data: self.data.map_inner(f),
+            label: self.label,
+        }
+    }
+}
+
+// Base arms: apply the transform to the leaf payload.
+macro_rules! impl_map_inner_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl<U> MapInner<U> for $t {
+                type Output = U;
+
+                fn map_inner<F>(self, f: F) -> Self::Output
+                where
+                    F: FnOnce(Self::Inner) -> U,
+                {
+                    f(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_map_inner_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Visitor over the layers of a wrapper stack
+// Each hook defaults to a no-op, so a visitor only overrides the layers it cares
+// about. `drive` walks top-to-bottom: it calls the hook for the current layer,
+// then recurses into the content, mirroring the `TypeVisitor` pattern.
+trait WrapperVisitor {
+    fn visit_usage(&mut self, _type_id: TypeId, _type_name: &'static str) {}
+    fn visit_changed(&mut self, _changed: bool) {}
+    fn visit_label(&mut self, _label: &str) {}
+    fn visit_leaf(&mut self, _leaf: &dyn std::fmt::Display) {}
+}
+
+trait Drive {
+    fn drive<V: WrapperVisitor>(&self, v: &mut V);
+}
+
+impl<U: 'static, T> Drive for Usage<U, T>
+where
+    T: Drive,
+{
+    fn drive<V: WrapperVisitor>(&self, v: &mut V) {
+        v.visit_usage(TypeId::of::<U>(), std::any::type_name::<U>());
+        self.data.drive(v);
+    }
+}
+
+impl<T> Drive for ChangedWrap<T>
+where
+    T: Drive,
+{
+    fn drive<V: WrapperVisitor>(&self, v: &mut V) {
+        v.visit_changed(self.changed);
+        self.data.drive(v);
+    }
+}
+
+impl<T> Drive for LabelWrap<T>
+where
+    T: Drive,
+{
+    fn drive<V: WrapperVisitor>(&self, v: &mut V) {
+        v.visit_label(&self.label);
+        self.data.drive(v);
+    }
+}
+
+macro_rules! impl_drive_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Drive for $t {
+                fn drive<V: WrapperVisitor>(&self, v: &mut V) {
+                    v.visit_leaf(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_drive_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Mutable sibling of `WrapperVisitor`, for cross-cutting in-place edits
+trait WrapperVisitorMut {
+    fn visit_usage_mut(&mut self, _type_id: TypeId, _type_name: &'static str) {}
+    fn visit_changed_mut(&mut self, _changed: &mut bool) {}
+    fn visit_label_mut(&mut self, _label: &mut Cow<'static, str>) {}
+}
+
+trait DriveMut {
+    fn drive_mut<V: WrapperVisitorMut>(&mut self, v: &mut V);
+}
+
+impl<U: 'static, T> DriveMut for Usage<U, T>
+where
+    T: DriveMut,
+{
+    fn drive_mut<V: WrapperVisitorMut>(&mut self, v: &mut V) {
+        v.visit_usage_mut(TypeId::of::<U>(), std::any::type_name::<U>());
+        self.data.drive_mut(v);
+    }
+}
+
+impl<T> DriveMut for ChangedWrap<T>
+where
+    T: DriveMut,
+{
+    fn drive_mut<V: WrapperVisitorMut>(&mut self, v: &mut V) {
+        v.visit_changed_mut(&mut self.changed);
+        self.data.drive_mut(v);
+    }
+}
+
+impl<T> DriveMut for LabelWrap<T>
+where
+    T: DriveMut,
+{
+    fn drive_mut<V: WrapperVisitorMut>(&mut self, v: &mut V) {
+        v.visit_label_mut(&mut self.label);
+        self.data.drive_mut(v);
+    }
+}
+
+macro_rules! impl_drive_mut_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DriveMut for $t {
+                fn drive_mut<V: WrapperVisitorMut>(&mut self, _v: &mut V) {}
+            }
+        )*
+    };
+}
+
+impl_drive_mut_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// A visitor that records a description of every layer in nesting order
+struct Describe {
+    layers: Vec<String>,
+}
+
+impl WrapperVisitor for Describe {
+    fn visit_usage(&mut self, _type_id: TypeId, type_name: &'static str) {
+        let short = type_name.rsplit("::").next().unwrap_or(type_name);
+        self.layers.push(format!("Usage<{}>", short));
+    }
+
+    fn visit_changed(&mut self, changed: bool) {
+        self.layers.push(format!("Changed({})", changed));
+    }
+
+    fn visit_label(&mut self, label: &str) {
+        self.layers.push(format!("Label({:?})", label));
+    }
+
+    fn visit_leaf(&mut self, leaf: &dyn std::fmt::Display) {
+        self.layers.push(format!("Leaf({})", leaf));
+    }
+}
+
+// A visitor that flips every changed flag in the stack, wherever it sits
+struct MarkDirty;
+
+impl WrapperVisitorMut for MarkDirty {
+    fn visit_changed_mut(&mut self, changed: &mut bool) {
+        *changed = true;
+    }
+}
+
+// Marker trait opting a wrapper into blanket trait-forwarding.
+// Restricting the forwarding blanket impls below to `Transparent` types keeps
+// them apart from a leaf type's own impl of the same downstream trait: the
+// blanket only covers types that opt in, and the leaf payloads never implement
+// `Transparent`. Being some wrapper's `Content` does not matter to coherence.
+trait Transparent: Wrapper {}
+
+impl<U, T> Transparent for Usage<U, T> {}
+impl<T> Transparent for ChangedWrap<T> {}
+impl<T> Transparent for LabelWrap<T> {}
+
+// Example downstream trait, defined with no knowledge of the wrapper stack.
+// One impl on the leaf types plus one blanket impl forwarding through any
+// `Transparent` wrapper is enough to make `render()` callable on the whole
+// stack, regardless of nesting order.
+trait Render {
+    fn render(&self) -> String;
+}
+
+impl<W> Render for W
+where
+    W: Transparent,
+    W::Content: Render,
+{
+    fn render(&self) -> String {
+        self.content().render()
+    }
+}
+
+macro_rules! impl_render_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Render for $t {
+                fn render(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_render_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 // Entrypoint
 fn main() {
     test_the_first();
     test_the_second();
+    test_the_third();
+    test_the_fourth();
+    test_the_fifth();
+    test_the_sixth();
 }
 
 // Let's see if this works...
@@ -417,3 +888,94 @@ fn test_the_second() {
     let label = label_changed_usage.get_label();
     println!("Label: {}", label);
 }
+
+// Now let's prove the lookup works in any order...
+fn test_the_third() {
+    // Construct permutations of our wrapper types
+    let mut usage_changed_label =
+        Usage::<UsageTag, _>::new(ChangedWrap::new(LabelWrap::new(1234)))
+            .with(Changed(true))
+            .with(Label("one"));
+
+    let label_changed_usage = LabelWrap::new(ChangedWrap::new(Usage::<UsageTag, _>::new(1234)))
+        .with(Changed(false))
+        .with(Label("six"));
+
+    // Retrieve a layer by type, regardless of where it sits in the stack
+    let changed: &ChangedWrap<_> = usage_changed_label.find();
+    println!("Changed: {}", changed.get_changed());
+    let changed: &ChangedWrap<_> = label_changed_usage.find();
+    println!("Changed: {}", changed.get_changed());
+
+    let label: &LabelWrap<_> = usage_changed_label.find();
+    println!("Label: {}", label.get_label());
+    let label: &LabelWrap<_> = label_changed_usage.find();
+    println!("Label: {}", label.get_label());
+
+    // Mutate a layer in place through the same type-directed lookup
+    let changed: &mut ChangedWrap<_> = usage_changed_label.find_mut();
+    changed.set_changed(false);
+    let changed: &ChangedWrap<_> = usage_changed_label.find();
+    println!("Changed: {}", changed.get_changed());
+
+    // Peel every layer to reach the innermost value and write through it,
+    // regardless of nesting order
+    *usage_changed_label.innermost_mut() += 1;
+    println!("Leaf: {}", usage_changed_label.innermost_mut());
+}
+
+// Now let's transform the core value without disturbing the wrapper stack...
+fn test_the_fourth() {
+    let label_changed_usage = LabelWrap::new(ChangedWrap::new(Usage::<UsageTag, _>::new(1234u32)))
+        .with(Changed(true))
+        .with(Label("six"));
+
+    // Recover the core payload, dropping every wrapper layer
+    let inner = LabelWrap::new(ChangedWrap::new(Usage::<UsageTag, _>::new(42u32))).into_innermost();
+    println!("Innermost: {}", inner);
+
+    // Map the leaf to a new type, preserving the label and changed flag
+    let mapped: LabelWrap<ChangedWrap<Usage<UsageTag, String>>> =
+        label_changed_usage.map_inner(|n: u32| n.to_string());
+    println!("Label: {}", mapped.get_label());
+    println!("Changed: {}", mapped.get_changed());
+    let leaf: &str = &mapped;
+    println!("Leaf: {}", leaf);
+}
+
+// Now let's walk every layer with a visitor, regardless of nesting order...
+fn test_the_fifth() {
+    let mut label_changed_usage =
+        LabelWrap::new(ChangedWrap::new(Usage::<UsageTag, _>::new(1234)))
+            .with(Changed(false))
+            .with(Label("one"));
+
+    // Collect a description of every layer in nesting order
+    let mut describe = Describe { layers: Vec::new() };
+    label_changed_usage.drive(&mut describe);
+    for layer in &describe.layers {
+        println!("{}", layer);
+    }
+
+    // Flip every changed flag in place, then confirm via the type-directed lookup
+    label_changed_usage.drive_mut(&mut MarkDirty);
+    let changed: &ChangedWrap<_> = label_changed_usage.find();
+    println!("Changed: {}", changed.get_changed());
+}
+
+// Now let's forward a trait we didn't define, through wrappers we didn't
+// write it for, regardless of nesting order...
+fn test_the_sixth() {
+    let usage_changed_label = Usage::<UsageTag, _>::new(ChangedWrap::new(LabelWrap::new(1234)))
+        .with(Changed(true))
+        .with(Label("one"));
+
+    let label_changed_usage = LabelWrap::new(ChangedWrap::new(Usage::<UsageTag, _>::new(1234)))
+        .with(Changed(false))
+        .with(Label("six"));
+
+    // `Render` is only implemented on the leaf integer types and, blanket,
+    // on `Transparent` wrappers. No wrapper-specific `Render` impl exists.
+    println!("Render: {}", usage_changed_label.render());
+    println!("Render: {}", label_changed_usage.render());
+}